@@ -3,6 +3,8 @@ mod shell_integration;
 
 use colored::*;
 use shell_integration::remove_shell_integration;
+use std::env;
+use std::fmt::Display;
 use std::fs;
 use std::io::{self, IsTerminal, Write};
 use std::process::Command;
@@ -28,6 +30,51 @@ const CTP_RED: colored::CustomColor = colored::CustomColor {
     b: 0xa8,
 };
 
+/// Rendering policy for status output.  `--plain` keeps everything ASCII-only
+/// and uncoloured for braille displays and logs; `NO_COLOR` only drops colour.
+struct Style {
+    plain: bool,
+    no_color: bool,
+}
+
+impl Style {
+    fn from_env() -> Self {
+        Self {
+            plain: env::args_os().skip(1).any(|a| a == "--plain"),
+            no_color: env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+        }
+    }
+
+    /// Colour goes to stderr, so decide on stderr rather than letting colored
+    /// look at stdout.  `CLICOLOR_FORCE` still wins unless `--plain` is given.
+    fn color(&self) -> bool {
+        if self.plain {
+            return false;
+        }
+        if env::var("CLICOLOR_FORCE").is_ok_and(|v| v != "0") {
+            return true;
+        }
+        !self.no_color && io::stderr().is_terminal()
+    }
+
+    fn dash(&self) -> &'static str {
+        if self.plain { "-" } else { "\u{2014}" }
+    }
+
+    fn ok(&self, msg: impl Display) {
+        let check = if self.plain { "ok" } else { "\u{2713}" };
+        eprintln!("  {} {}", check.custom_color(CTP_GREEN), msg);
+    }
+
+    fn warning(&self, msg: impl Display) {
+        eprintln!("  {} {}", "warning:".custom_color(CTP_YELLOW), msg);
+    }
+
+    fn error(&self, msg: impl Display) {
+        eprintln!("  {} {}", "error:".custom_color(CTP_RED), msg);
+    }
+}
+
 fn confirm(prompt: &str) -> bool {
     eprint!("{} [Y/n] ", prompt);
     io::stderr().flush().ok();
@@ -68,18 +115,21 @@ fn migrate_config() -> bool {
     copied
 }
 
-fn run_cargo(args: &[&str]) -> bool {
-    Command::new("cargo")
-        .args(args)
+fn run_cargo(args: &[&str], style: &Style) -> bool {
+    let mut cmd = Command::new("cargo");
+    if style.plain || style.no_color {
+        cmd.args(["--color", "never"])
+            .env("CARGO_TERM_PROGRESS_WHEN", "never");
+    }
+    cmd.args(args)
         .status()
         .map(|s| s.success())
         .unwrap_or(false)
 }
 
 fn main() {
-    if std::io::stderr().is_terminal() {
-        colored::control::set_override(true);
-    }
+    let style = Style::from_env();
+    colored::control::set_override(style.color());
 
     eprintln!(
         "{}",
@@ -90,23 +140,22 @@ fn main() {
     eprintln!();
 
     match remove_shell_integration() {
-        Ok(true) => eprintln!(
-            "  {} removed nlsh-rs shell integration",
-            "\u{2713}".custom_color(CTP_GREEN)
-        ),
-        Ok(false) => {}
-        Err(e) => eprintln!(
-            "  {} could not remove shell integration: {}",
-            "warning:".custom_color(CTP_YELLOW),
-            e
-        ),
+        Ok(removal) => {
+            if removal.removed {
+                style.ok("removed nlsh-rs shell integration");
+            }
+            if let Some(path) = removal.kept_fish_function {
+                style.warning(format!(
+                    "kept {}: not the nlsh-rs shell integration",
+                    path.display()
+                ));
+            }
+        }
+        Err(e) => style.warning(format!("could not remove shell integration: {}", e)),
     }
 
     if migrate_config() {
-        eprintln!(
-            "  {} migrated config to ~/.config/larpshell/",
-            "\u{2713}".custom_color(CTP_GREEN)
-        );
+        style.ok("migrated config to ~/.config/larpshell/");
     }
 
     eprintln!();
@@ -117,32 +166,23 @@ fn main() {
     )) {
         eprintln!();
 
-        let uninstalled = run_cargo(&["uninstall", "nlsh-rs"]);
+        let uninstalled = run_cargo(&["uninstall", "nlsh-rs"], &style);
         if uninstalled {
-            eprintln!(
-                "  {} uninstalled nlsh-rs",
-                "\u{2713}".custom_color(CTP_GREEN)
-            );
+            style.ok("uninstalled nlsh-rs");
         } else {
-            eprintln!(
-                "  {} failed to uninstall nlsh-rs",
-                "warning:".custom_color(CTP_YELLOW)
-            );
+            style.warning("failed to uninstall nlsh-rs");
         }
 
         eprintln!();
 
-        let installed = run_cargo(&["install", "larpshell"]);
+        let installed = run_cargo(&["install", "larpshell"], &style);
         if installed {
-            eprintln!(
-                "  {} installed larpshell",
-                "\u{2713}".custom_color(CTP_GREEN)
-            );
+            style.ok("installed larpshell");
         } else {
-            eprintln!(
-                "  {} cargo install larpshell failed — run it manually",
-                "error:".custom_color(CTP_RED)
-            );
+            style.error(format!(
+                "cargo install larpshell failed {} run it manually",
+                style.dash()
+            ));
         }
 
         eprintln!();
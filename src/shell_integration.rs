@@ -1,7 +1,23 @@
 use std::fs::{self};
+use std::path::PathBuf;
 
 use crate::cli::get_home_dir;
 
+/// Result of looking for the fish wrapper in `~/.config/fish/functions`.
+pub enum FishRemoval {
+    Removed,
+    /// An `nlsh-rs.fish` that is not the generated wrapper, e.g. one written
+    /// by `alias --save nlsh-rs=larpshell`.  Left in place.
+    KeptUserFunction(PathBuf),
+    Absent,
+}
+
+/// What `remove_shell_integration` did, for `main` to report.
+pub struct ShellRemoval {
+    pub removed: bool,
+    pub kept_fish_function: Option<PathBuf>,
+}
+
 /// Removes a marked function block from shell config content.
 /// Looks for `marker` as a comment line, then tracks brace depth starting from
 /// the line matching `function_sig` until braces balance to zero.
@@ -74,16 +90,32 @@ pub fn remove_bash_integration() -> Result<bool, Box<dyn std::error::Error>> {
     Ok(found)
 }
 
-pub fn remove_fish_integration() -> Result<bool, Box<dyn std::error::Error>> {
+pub fn remove_fish_integration() -> Result<FishRemoval, Box<dyn std::error::Error>> {
     let home = get_home_dir();
     let fish_function_path = home.join(".config/fish/functions/nlsh-rs.fish");
 
-    if fish_function_path.exists() {
-        fs::remove_file(&fish_function_path)?;
-        Ok(true)
-    } else {
-        Ok(false)
+    if !fish_function_path.exists() {
+        return Ok(FishRemoval::Absent);
     }
+
+    let content = fs::read_to_string(&fish_function_path)?;
+
+    if !is_generated_fish_wrapper(&content) {
+        return Ok(FishRemoval::KeptUserFunction(fish_function_path));
+    }
+
+    fs::remove_file(&fish_function_path)?;
+    Ok(FishRemoval::Removed)
+}
+
+/// The generated wrapper carries the integration marker and calls the binary
+/// through `command nlsh-rs`; user functions such as the one written by
+/// `alias --save nlsh-rs=larpshell` have neither.
+fn is_generated_fish_wrapper(content: &str) -> bool {
+    content
+        .lines()
+        .any(|l| l.trim() == "# nlsh-rs shell integration")
+        || content.contains("command nlsh-rs")
 }
 
 fn remove_bash_autocomplete() -> Result<bool, Box<dyn std::error::Error>> {
@@ -182,9 +214,77 @@ fn remove_autocomplete() -> Result<bool, Box<dyn std::error::Error>> {
     Ok(bash_removed || zsh_removed || fish_removed)
 }
 
-pub fn remove_shell_integration() -> Result<bool, Box<dyn std::error::Error>> {
+pub fn remove_shell_integration() -> Result<ShellRemoval, Box<dyn std::error::Error>> {
     let bash_removed = remove_bash_integration()?;
-    let fish_removed = remove_fish_integration()?;
+    let fish = remove_fish_integration()?;
     let autocomplete_removed = remove_autocomplete()?;
-    Ok(bash_removed || fish_removed || autocomplete_removed)
+
+    let (fish_removed, kept_fish_function) = match fish {
+        FishRemoval::Removed => (true, None),
+        FishRemoval::KeptUserFunction(path) => (false, Some(path)),
+        FishRemoval::Absent => (false, None),
+    };
+
+    Ok(ShellRemoval {
+        removed: bash_removed || fish_removed || autocomplete_removed,
+        kept_fish_function,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `~/.config/fish/functions/nlsh-rs.fish` exactly as written by
+    /// `setup_fish_integration` in nlsh-rs 0.2.2 (unchanged since 0.2.1).
+    const FISH_WRAPPER_0_2: &str = r#"# nlsh-rs shell integration
+function nlsh-rs
+    if test (count $argv) -eq 0
+        command nlsh-rs
+        return $status
+    end
+
+    switch $argv[1]
+        case api explain uninstall prompt -- help --help -h --version -V
+            command nlsh-rs $argv
+            return $status
+    end
+
+    set cmd (command nlsh-rs $argv)
+    set exit_code $status
+    if test $exit_code -eq 0 -a -n "$cmd"
+        if string match -qr '^(Usage:|error:|Commands:|nlsh-rs [0-9]|\x1b|\e|✓|.*:$)' -- "$cmd"
+            echo "$cmd"
+            return 0
+        end
+        eval $cmd
+    else
+        return $exit_code
+    end
+end
+"#;
+
+    /// Output of `alias --save nlsh-rs=larpshell` in fish.
+    const FISH_ALIAS: &str = "# Defined via `source`
+function nlsh-rs --wraps=larpshell --description 'alias nlsh-rs=larpshell'
+  larpshell $argv
+        
+end
+";
+
+    #[test]
+    fn generated_wrapper_is_removed() {
+        assert!(is_generated_fish_wrapper(FISH_WRAPPER_0_2));
+    }
+
+    #[test]
+    fn fish_alias_is_kept() {
+        assert!(!is_generated_fish_wrapper(FISH_ALIAS));
+    }
+
+    #[test]
+    fn markerless_user_function_is_kept() {
+        let user_fn = "function nlsh-rs\n    larpshell --plain $argv\nend\n";
+        assert!(!is_generated_fish_wrapper(user_fn));
+    }
 }